@@ -0,0 +1,124 @@
+//! Optional `pyarrow.Table` export, gated behind the `arrow` feature so
+//! the core extension doesn't pull in the Arrow dependency tree unless a
+//! caller actually wants it — mirroring how `arrow-rs` itself keeps its
+//! format integrations (CSV, Parquet, ...) behind features.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
+use arrow::pyarrow::ToPyArrow;
+use arrow::record_batch::RecordBatch;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::typed::ColumnType;
+
+fn cell_type_error(row: usize, col: usize, cell: &str, kind: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "row {}, column {}: {:?} is not a valid {}",
+        row, col, cell, kind
+    ))
+}
+
+/// Build one Arrow array for a column, per its resolved type.
+///
+/// `decimal` columns are exported as UTF8 rather than Arrow's
+/// `Decimal128`, since that type needs one fixed precision/scale for the
+/// whole column and NSV cells carry neither declaration; this keeps the
+/// conversion lossless instead of guessing a scale.
+///
+/// A cell that doesn't match its column's declared or inferred type
+/// raises a `ValueError` naming the row/column, the same as
+/// [`loads_typed`](crate::loads_typed)/[`load_columns`](crate::load_columns) —
+/// `to_arrow` does not silently turn malformed cells into Arrow nulls.
+fn build_column(col_idx: usize, cells: &[&str], ty: ColumnType) -> PyResult<ArrayRef> {
+    match ty {
+        ColumnType::Int => {
+            let mut values = Vec::with_capacity(cells.len());
+            for (row_idx, cell) in cells.iter().enumerate() {
+                values.push(
+                    cell.parse::<i64>()
+                        .map_err(|_| cell_type_error(row_idx, col_idx, cell, "int"))?,
+                );
+            }
+            Ok(Arc::new(Int64Array::from_iter_values(values)) as ArrayRef)
+        }
+        ColumnType::Float => {
+            let mut values = Vec::with_capacity(cells.len());
+            for (row_idx, cell) in cells.iter().enumerate() {
+                values.push(
+                    cell.parse::<f64>()
+                        .map_err(|_| cell_type_error(row_idx, col_idx, cell, "float"))?,
+                );
+            }
+            Ok(Arc::new(Float64Array::from_iter_values(values)) as ArrayRef)
+        }
+        ColumnType::Bool => {
+            let mut values = Vec::with_capacity(cells.len());
+            for (row_idx, cell) in cells.iter().enumerate() {
+                let value = match *cell {
+                    "true" | "True" | "1" => true,
+                    "false" | "False" | "0" => false,
+                    _ => return Err(cell_type_error(row_idx, col_idx, cell, "bool")),
+                };
+                values.push(value);
+            }
+            Ok(Arc::new(BooleanArray::from(values)) as ArrayRef)
+        }
+        ColumnType::Str | ColumnType::Decimal => {
+            Ok(Arc::new(StringArray::from_iter_values(cells.iter())) as ArrayRef)
+        }
+    }
+}
+
+/// Transpose `rows` into Arrow columns and hand them to pyarrow as a
+/// `Table`, moving data across the C Data Interface rather than copying
+/// through Python objects.
+pub fn to_table(
+    py: Python,
+    names: &[String],
+    rows: &[Vec<String>],
+    column_types: &[ColumnType],
+) -> PyResult<PyObject> {
+    let mut arrays = Vec::with_capacity(names.len());
+    for (col_idx, _) in names.iter().enumerate() {
+        let cells: Vec<&str> = rows
+            .iter()
+            .map(|r| r.get(col_idx).map(|s| s.as_str()).unwrap_or(""))
+            .collect();
+        arrays.push(build_column(col_idx, &cells, column_types[col_idx])?);
+    }
+
+    let batch = RecordBatch::try_from_iter(names.iter().cloned().zip(arrays.into_iter()))
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let py_batch = batch.to_pyarrow(py)?;
+    let pyarrow = py.import("pyarrow")?;
+    let table = pyarrow
+        .getattr("Table")?
+        .call_method1("from_batches", ([py_batch],))?;
+    Ok(table.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A cell that doesn't match its declared `int` type must raise,
+    /// not silently become an Arrow null (the behavior before this fix).
+    #[test]
+    fn build_column_raises_on_cell_that_does_not_match_declared_type() {
+        pyo3::prepare_freethreaded_python();
+        let err = build_column(0, &["1", "not-a-number", "3"], ColumnType::Int)
+            .expect_err("malformed int cell should raise");
+        let message = err.to_string();
+        assert!(message.contains("row 1"));
+        assert!(message.contains("int"));
+    }
+
+    #[test]
+    fn build_column_parses_valid_int_column() {
+        let array = build_column(0, &["1", "2", "3"], ColumnType::Int).unwrap();
+        assert_eq!(array.len(), 3);
+    }
+}
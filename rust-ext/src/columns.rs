@@ -0,0 +1,83 @@
+//! Columnar access: transpose a parsed NSV grid into a `dict[str, list]`
+//! once in Rust, instead of making every caller do it in Python.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::typed::{self, ColumnType};
+
+/// Transpose `rows` into a dict keyed by `names`, converting each column
+/// according to `column_types`.
+pub fn build_dict(
+    py: Python,
+    names: &[String],
+    rows: &[Vec<String>],
+    column_types: &[ColumnType],
+) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+
+    for (col_idx, name) in names.iter().enumerate() {
+        let mut values = Vec::with_capacity(rows.len());
+        for (row_idx, row) in rows.iter().enumerate() {
+            let cell = row.get(col_idx).map(|s| s.as_str()).unwrap_or("");
+            values.push(typed::convert_cell(py, cell, column_types[col_idx], row_idx, col_idx)?);
+        }
+        dict.set_item(name, values)?;
+    }
+
+    Ok(dict.into())
+}
+
+/// Column names: the header row if present, else positional `"0"`, `"1"`, ...
+/// A data column beyond the end of the header gets its positional name.
+pub fn column_names(header: Option<&[String]>, n_cols: usize) -> Vec<String> {
+    match header {
+        Some(names) => {
+            let mut names = names.to_vec();
+            names.extend((names.len()..n_cols).map(|i| i.to_string()));
+            names
+        }
+        None => (0..n_cols).map(|i| i.to_string()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typed::resolve_schema;
+
+    /// A header with no data rows at all must not panic: `column_types`
+    /// has to come out at least as long as `names`.
+    #[test]
+    fn build_dict_handles_header_only_table() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let header = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+            let rows: Vec<Vec<String>> = vec![];
+            let n_cols = crate::widest_row(Some(&header), &rows);
+            let names = column_names(Some(&header), n_cols);
+            let column_types = resolve_schema(None, &rows, n_cols).unwrap();
+
+            let dict = build_dict(py, &names, &rows, &column_types).unwrap();
+            let dict = dict.as_ref(py).downcast::<PyDict>().unwrap();
+            assert_eq!(dict.len(), 3);
+        });
+    }
+
+    /// A header wider than its data rows must not panic either.
+    #[test]
+    fn build_dict_handles_header_wider_than_data() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let header = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+            let rows = vec![vec!["1".to_string()]];
+            let n_cols = crate::widest_row(Some(&header), &rows);
+            let names = column_names(Some(&header), n_cols);
+            let column_types = resolve_schema(None, &rows, n_cols).unwrap();
+
+            let dict = build_dict(py, &names, &rows, &column_types).unwrap();
+            let dict = dict.as_ref(py).downcast::<PyDict>().unwrap();
+            assert_eq!(dict.len(), 3);
+        });
+    }
+}
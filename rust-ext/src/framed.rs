@@ -0,0 +1,141 @@
+//! Netstring-framed cell encoding (`<byte-length>:<bytes>,`), used as an
+//! opt-in binary-safe alternative to NSV's plain separator-delimited
+//! layout. Framing lets a cell contain the record/field separator or a
+//! newline without ambiguity, at the cost of a slightly larger encoding.
+//!
+//! A row is itself framed as a netstring whose payload is the
+//! concatenation of its cells' netstrings, so row boundaries are
+//! recovered the same way cell boundaries are: no external separator is
+//! needed at either level.
+
+use std::fmt;
+
+/// A malformed netstring was encountered while decoding framed cells.
+#[derive(Debug)]
+pub struct FramingError(pub String);
+
+impl fmt::Display for FramingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid netstring framing: {}", self.0)
+    }
+}
+
+impl std::error::Error for FramingError {}
+
+fn encode_netstring(payload: &str, out: &mut String) {
+    out.push_str(&payload.len().to_string());
+    out.push(':');
+    out.push_str(payload);
+    out.push(',');
+}
+
+/// Read one netstring starting at `pos`, returning its payload and the
+/// position just past the trailing comma.
+fn decode_netstring(s: &str, pos: usize) -> Result<(&str, usize), FramingError> {
+    let bytes = s.as_bytes();
+    let colon = bytes[pos..]
+        .iter()
+        .position(|&b| b == b':')
+        .ok_or_else(|| FramingError("missing ':' after length".to_string()))?;
+    let len_str = &s[pos..pos + colon];
+    let len: usize = len_str
+        .parse()
+        .map_err(|_| FramingError(format!("invalid length {:?}", len_str)))?;
+
+    let start = pos + colon + 1;
+    let end = start
+        .checked_add(len)
+        .ok_or_else(|| FramingError("netstring length overflows".to_string()))?;
+    if end >= bytes.len() {
+        return Err(FramingError(
+            "netstring length runs past end of input".to_string(),
+        ));
+    }
+    let payload = s
+        .get(start..end)
+        .ok_or_else(|| FramingError("netstring length splits a UTF-8 character".to_string()))?;
+    if bytes[end] != b',' {
+        return Err(FramingError("missing trailing ',' after netstring".to_string()));
+    }
+
+    Ok((payload, end + 1))
+}
+
+/// Encode a full grid of rows as nested netstrings: cells within a row,
+/// rows within the document.
+pub fn encode(data: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for row in data {
+        let mut row_payload = String::new();
+        for cell in row {
+            encode_netstring(cell, &mut row_payload);
+        }
+        encode_netstring(&row_payload, &mut out);
+    }
+    out
+}
+
+/// Decode a netstring-framed document back into rows.
+pub fn decode(s: &str) -> Result<Vec<Vec<String>>, FramingError> {
+    let mut rows = Vec::new();
+    let mut pos = 0;
+    while pos < s.len() {
+        let (row_payload, next) = decode_netstring(s, pos)?;
+        pos = next;
+
+        let mut cells = Vec::new();
+        let mut cell_pos = 0;
+        while cell_pos < row_payload.len() {
+            let (cell, next_cell) = decode_netstring(row_payload, cell_pos)?;
+            cells.push(cell.to_string());
+            cell_pos = next_cell;
+        }
+        rows.push(cells);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_plain_cells() {
+        let data = vec![
+            vec!["a".to_string(), "b".to_string()],
+            vec!["c".to_string(), "d".to_string()],
+        ];
+        let encoded = encode(&data);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn round_trips_cells_containing_separators_and_newlines() {
+        let data = vec![vec!["a,b\nc".to_string(), "d\n\ne".to_string()]];
+        let encoded = encode(&data);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn round_trips_empty_cells_and_rows() {
+        let data = vec![vec!["".to_string(), "".to_string()], vec![]];
+        let encoded = encode(&data);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn decode_rejects_missing_trailing_comma() {
+        assert!(decode("3:abcX").is_err());
+    }
+
+    #[test]
+    fn decode_rejects_length_that_would_overflow_usize_instead_of_panicking() {
+        let oversized = format!("{}:x,", usize::MAX);
+        assert!(decode(&oversized).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_truncated_length() {
+        assert!(decode("10:short,").is_err());
+    }
+}
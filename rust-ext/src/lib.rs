@@ -1,10 +1,34 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+
+use pyo3::exceptions::{PyFileNotFoundError, PyOSError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
-/// Parse NSV string into a list of lists
+mod agg;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod columns;
+mod framed;
+mod reader;
+mod typed;
+
+use agg::{EWMean, Max, Mean, Min, Quantile, Variance};
+use reader::{dump_rows, NsvReader};
+
+/// Parse NSV string into a list of lists.
+///
+/// If `framed` is true, cells are decoded as netstrings
+/// (`<byte-length>:<bytes>,`) instead of the plain separator-delimited
+/// layout, so cells may contain the record/field separator or a newline.
 #[pyfunction]
-fn loads(py: Python, s: &str) -> PyResult<PyObject> {
-    let data = nsv::loads(s);
+#[pyo3(signature = (s, framed = false))]
+fn loads(py: Python, s: &str, framed: bool) -> PyResult<PyObject> {
+    let data = if framed {
+        framed::decode(s).map_err(|e| PyValueError::new_err(e.to_string()))?
+    } else {
+        nsv::loads(s)
+    };
 
     // Convert Vec<Vec<String>> to Python list of lists
     let result = PyList::empty(py);
@@ -19,10 +43,214 @@ fn loads(py: Python, s: &str) -> PyResult<PyObject> {
     Ok(result.into())
 }
 
-/// Serialize data to NSV string
+/// Serialize data to an NSV string.
+///
+/// If `framed` is true, cells are encoded as netstrings instead of the
+/// plain separator-delimited layout, making the output binary-safe
+/// regardless of cell contents.
+#[pyfunction]
+#[pyo3(signature = (data, framed = false))]
+fn dumps(data: Vec<Vec<String>>, framed: bool) -> PyResult<String> {
+    if framed {
+        Ok(framed::encode(&data))
+    } else {
+        Ok(nsv::dumps(&data))
+    }
+}
+
+/// Map a file I/O error to the appropriate Python exception: a missing
+/// path becomes `FileNotFoundError`, anything else (permission denied,
+/// path is a directory, invalid UTF-8, ...) becomes `OSError`.
+fn io_error_to_py(path: &str, e: &io::Error) -> PyErr {
+    match e.kind() {
+        ErrorKind::NotFound => PyFileNotFoundError::new_err(format!("{}: {}", path, e)),
+        _ => PyOSError::new_err(format!("{}: {}", path, e)),
+    }
+}
+
+/// Read and parse an NSV file directly, without going through a Python
+/// string first. The file read and the parse both run with the GIL
+/// released so other Python threads can make progress.
+#[pyfunction]
+fn load(py: Python, path: &str) -> PyResult<PyObject> {
+    let data = py
+        .allow_threads(|| {
+            let contents = fs::read_to_string(path)?;
+            Ok::<_, io::Error>(nsv::loads(&contents))
+        })
+        .map_err(|e| io_error_to_py(path, &e))?;
+
+    let result = PyList::empty(py);
+    for row in data {
+        let py_row = PyList::empty(py);
+        for cell in row {
+            py_row.append(cell)?;
+        }
+        result.append(py_row)?;
+    }
+    Ok(result.into())
+}
+
+/// Serialize data and write it straight to `path`, without materializing
+/// the whole NSV string in Python first. The serialize and the file
+/// write both run with the GIL released.
 #[pyfunction]
-fn dumps(data: Vec<Vec<String>>) -> PyResult<String> {
-    Ok(nsv::dumps(&data))
+fn dump(py: Python, data: Vec<Vec<String>>, path: &str) -> PyResult<()> {
+    py.allow_threads(|| {
+        let serialized = nsv::dumps(&data);
+        fs::write(path, serialized).map_err(|e| PyValueError::new_err(format!("{}: {}", path, e)))
+    })
+}
+
+/// Parse NSV string into a list of rows of typed Python values instead
+/// of strings.
+///
+/// `schema` is a per-column list naming the target type
+/// (`"int"`/`"float"`/`"decimal"`/`"bool"`/`"str"`), or `None` entries
+/// (or an entirely `None` schema) to auto-infer a column's type from its
+/// values, falling back to `str` when the column is heterogeneous. A
+/// cell that doesn't match its column's type raises a `ValueError`
+/// naming the row and column.
+#[pyfunction]
+#[pyo3(signature = (s, schema = None))]
+fn loads_typed(py: Python, s: &str, schema: Option<Vec<Option<String>>>) -> PyResult<PyObject> {
+    let rows = nsv::loads(s);
+    let n_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let column_types = typed::resolve_schema(schema, &rows, n_cols)?;
+
+    let result = PyList::empty(py);
+    for (row_idx, row) in rows.iter().enumerate() {
+        let py_row = PyList::empty(py);
+        for (col_idx, cell) in row.iter().enumerate() {
+            let value = typed::convert_cell(py, cell, column_types[col_idx], row_idx, col_idx)?;
+            py_row.append(value)?;
+        }
+        result.append(py_row)?;
+    }
+    Ok(result.into())
+}
+
+/// Column count to size the schema/names to: the widest of the header
+/// row (if any) and every data row, so a header wider than its data (or
+/// a header-only table with no data rows at all) doesn't leave
+/// `column_types` shorter than `names`.
+fn widest_row(header_row: Option<&[String]>, rows: &[Vec<String>]) -> usize {
+    header_row
+        .map(|h| h.len())
+        .into_iter()
+        .chain(rows.iter().map(|r| r.len()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Parse NSV string into a `dict[str, list]` keyed by column, transposing
+/// the grid once in Rust instead of leaving callers to do it in Python.
+///
+/// The first row is treated as the header (column names) unless `header`
+/// is false, in which case columns are named positionally (`"0"`,
+/// `"1"`, ...). `schema` behaves as in [`loads_typed`].
+#[pyfunction]
+#[pyo3(signature = (s, schema = None, header = true))]
+fn load_columns(
+    py: Python,
+    s: &str,
+    schema: Option<Vec<Option<String>>>,
+    header: bool,
+) -> PyResult<PyObject> {
+    let mut rows = nsv::loads(s);
+    let header_row = if header && !rows.is_empty() {
+        Some(rows.remove(0))
+    } else {
+        None
+    };
+
+    let n_cols = widest_row(header_row.as_deref(), &rows);
+    let names = columns::column_names(header_row.as_deref(), n_cols);
+    let column_types = typed::resolve_schema(schema, &rows, n_cols)?;
+
+    columns::build_dict(py, &names, &rows, &column_types)
+}
+
+/// Parse NSV string and hand it straight to pyarrow as a `Table`, moving
+/// data across the Arrow C Data Interface rather than through Python
+/// objects. Requires the `arrow` feature.
+///
+/// `schema`/`header` behave as in [`load_columns`]. As with
+/// [`loads_typed`], a cell that doesn't match its column's type raises a
+/// `ValueError` naming the row/column rather than becoming an Arrow null.
+#[cfg(feature = "arrow")]
+#[pyfunction]
+#[pyo3(signature = (s, schema = None, header = true))]
+fn to_arrow(
+    py: Python,
+    s: &str,
+    schema: Option<Vec<Option<String>>>,
+    header: bool,
+) -> PyResult<PyObject> {
+    let mut rows = nsv::loads(s);
+    let header_row = if header && !rows.is_empty() {
+        Some(rows.remove(0))
+    } else {
+        None
+    };
+
+    let n_cols = widest_row(header_row.as_deref(), &rows);
+    let names = columns::column_names(header_row.as_deref(), n_cols);
+    let column_types = typed::resolve_schema(schema, &rows, n_cols)?;
+
+    arrow::to_table(py, &names, &rows, &column_types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Error, ErrorKind};
+
+    #[test]
+    fn io_error_to_py_maps_not_found_to_file_not_found_error() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = io_error_to_py("missing.nsv", &Error::new(ErrorKind::NotFound, "nope"));
+            assert!(err.is_instance_of::<pyo3::exceptions::PyFileNotFoundError>(py));
+        });
+    }
+
+    #[test]
+    fn io_error_to_py_maps_other_kinds_to_os_error() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = io_error_to_py(
+                "bad.nsv",
+                &Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8"),
+            );
+            assert!(err.is_instance_of::<pyo3::exceptions::PyOSError>(py));
+        });
+    }
+
+    #[test]
+    fn widest_row_is_max_of_header_and_data() {
+        let header = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let rows = vec![vec!["1".to_string(), "2".to_string()]];
+        assert_eq!(widest_row(Some(&header), &rows), 3);
+    }
+
+    #[test]
+    fn widest_row_header_only_no_data_rows() {
+        let header = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(widest_row(Some(&header), &[]), 3);
+    }
+
+    #[test]
+    fn widest_row_data_wider_than_header() {
+        let header = vec!["a".to_string()];
+        let rows = vec![vec!["1".to_string(), "2".to_string(), "3".to_string()]];
+        assert_eq!(widest_row(Some(&header), &rows), 3);
+    }
+
+    #[test]
+    fn widest_row_no_header_no_rows() {
+        assert_eq!(widest_row(None, &[]), 0);
+    }
 }
 
 /// A Python module implemented in Rust.
@@ -30,5 +258,19 @@ fn dumps(data: Vec<Vec<String>>) -> PyResult<String> {
 fn nsv_rust_ext(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(loads, m)?)?;
     m.add_function(wrap_pyfunction!(dumps, m)?)?;
+    m.add_function(wrap_pyfunction!(loads_typed, m)?)?;
+    m.add_function(wrap_pyfunction!(load_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(load, m)?)?;
+    m.add_function(wrap_pyfunction!(dump, m)?)?;
+    m.add_function(wrap_pyfunction!(dump_rows, m)?)?;
+    m.add_class::<NsvReader>()?;
+    m.add_class::<Mean>()?;
+    m.add_class::<Variance>()?;
+    m.add_class::<EWMean>()?;
+    m.add_class::<Quantile>()?;
+    m.add_class::<Min>()?;
+    m.add_class::<Max>()?;
+    #[cfg(feature = "arrow")]
+    m.add_function(wrap_pyfunction!(to_arrow, m)?)?;
     Ok(())
 }
@@ -0,0 +1,210 @@
+//! Schema-aware typed parsing: convert NSV cells straight into
+//! `int`/`float`/`Decimal`/`bool`/`str` instead of leaving every caller
+//! to re-parse strings.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// A column's target type, either declared by the caller or inferred
+/// from its values.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Int,
+    Float,
+    Decimal,
+    Bool,
+    Str,
+}
+
+impl ColumnType {
+    fn parse_name(name: &str) -> PyResult<Self> {
+        match name {
+            "int" => Ok(ColumnType::Int),
+            "float" => Ok(ColumnType::Float),
+            "decimal" => Ok(ColumnType::Decimal),
+            "bool" => Ok(ColumnType::Bool),
+            "str" => Ok(ColumnType::Str),
+            other => Err(PyValueError::new_err(format!(
+                "unknown schema type {:?}, expected one of int/float/decimal/bool/str",
+                other
+            ))),
+        }
+    }
+}
+
+/// Resolve the schema into one `ColumnType` per column, inferring any
+/// column left as `None` from its own values.
+pub fn resolve_schema(
+    schema: Option<Vec<Option<String>>>,
+    rows: &[Vec<String>],
+    n_cols: usize,
+) -> PyResult<Vec<ColumnType>> {
+    let declared: Vec<Option<String>> = match schema {
+        Some(s) => s,
+        None => vec![None; n_cols],
+    };
+
+    let mut resolved = Vec::with_capacity(n_cols);
+    for col in 0..n_cols {
+        match declared.get(col).and_then(|c| c.clone()) {
+            Some(name) => resolved.push(ColumnType::parse_name(&name)?),
+            None => resolved.push(infer_column(rows, col)),
+        }
+    }
+    Ok(resolved)
+}
+
+/// Infer a column's type from its values: `int` if every cell parses as
+/// an integer, else `float`, else `bool`, else fall back to `str`.
+///
+/// A column with no cells at all (e.g. a header wider than every data
+/// row) has nothing to infer from, so it falls back to `str` rather than
+/// vacuously satisfying every `all()` check below and being misinferred
+/// as `int`.
+fn infer_column(rows: &[Vec<String>], col: usize) -> ColumnType {
+    let cells: Vec<&str> = rows.iter().filter_map(|r| r.get(col)).map(|s| s.as_str()).collect();
+
+    if cells.is_empty() {
+        return ColumnType::Str;
+    }
+
+    if cells.iter().all(|c| c.parse::<i64>().is_ok()) {
+        return ColumnType::Int;
+    }
+    if cells.iter().all(|c| c.parse::<f64>().is_ok()) {
+        return ColumnType::Float;
+    }
+    if cells.iter().all(|c| parse_bool(c).is_some()) {
+        return ColumnType::Bool;
+    }
+    ColumnType::Str
+}
+
+fn parse_bool(s: &str) -> Option<bool> {
+    match s {
+        "true" | "True" | "1" => Some(true),
+        "false" | "False" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Convert one cell to its Python value per `ty`, raising a `ValueError`
+/// naming the offending row/column on failure.
+pub fn convert_cell(py: Python, cell: &str, ty: ColumnType, row: usize, col: usize) -> PyResult<PyObject> {
+    let err = |kind: &str| {
+        PyValueError::new_err(format!(
+            "row {}, column {}: {:?} is not a valid {}",
+            row, col, cell, kind
+        ))
+    };
+
+    match ty {
+        ColumnType::Int => cell
+            .parse::<i64>()
+            .map(|v| v.into_py(py))
+            .map_err(|_| err("int")),
+        ColumnType::Float => cell
+            .parse::<f64>()
+            .map(|v| v.into_py(py))
+            .map_err(|_| err("float")),
+        ColumnType::Bool => parse_bool(cell).map(|v| v.into_py(py)).ok_or_else(|| err("bool")),
+        ColumnType::Str => Ok(cell.into_py(py)),
+        ColumnType::Decimal => {
+            let decimal = Decimal::from_str(cell).map_err(|_| err("decimal"))?;
+            to_py_decimal(py, &decimal)
+        }
+    }
+}
+
+/// Build a `decimal.Decimal` from a `rust_decimal::Decimal` via its
+/// canonical string form — there is no C API for `Decimal`, so this is
+/// the standard PyO3 conversion pattern for it.
+fn to_py_decimal(py: Python, value: &Decimal) -> PyResult<PyObject> {
+    let decimal_module = py.import("decimal")?;
+    let py_decimal = decimal_module.getattr("Decimal")?.call1((value.to_string(),))?;
+    Ok(py_decimal.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(cells: &[&[&str]]) -> Vec<Vec<String>> {
+        cells
+            .iter()
+            .map(|row| row.iter().map(|c| c.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn infers_int_column() {
+        let data = rows(&[&["1"], &["2"], &["3"]]);
+        assert!(matches!(infer_column(&data, 0), ColumnType::Int));
+    }
+
+    #[test]
+    fn infers_float_when_not_all_int() {
+        let data = rows(&[&["1"], &["2.5"], &["3"]]);
+        assert!(matches!(infer_column(&data, 0), ColumnType::Float));
+    }
+
+    #[test]
+    fn infers_bool_column() {
+        let data = rows(&[&["true"], &["False"], &["1"]]);
+        assert!(matches!(infer_column(&data, 0), ColumnType::Bool));
+    }
+
+    #[test]
+    fn falls_back_to_str_for_heterogeneous_column() {
+        let data = rows(&[&["1"], &["hello"], &["true"]]);
+        assert!(matches!(infer_column(&data, 0), ColumnType::Str));
+    }
+
+    #[test]
+    fn falls_back_to_str_for_column_with_no_cells() {
+        // A header wider than every data row: column 1 has no cells to
+        // infer from at all, not even an empty string.
+        let data = rows(&[&["1"]]);
+        assert!(matches!(infer_column(&data, 1), ColumnType::Str));
+    }
+
+    #[test]
+    fn resolve_schema_prefers_declared_type_over_inference() {
+        let data = rows(&[&["1"], &["2"]]);
+        let schema = Some(vec![Some("str".to_string())]);
+        let resolved = resolve_schema(schema, &data, 1).unwrap();
+        assert!(matches!(resolved[0], ColumnType::Str));
+    }
+
+    #[test]
+    fn resolve_schema_rejects_unknown_type_name() {
+        let data = rows(&[&["1"]]);
+        let schema = Some(vec![Some("hex".to_string())]);
+        assert!(resolve_schema(schema, &data, 1).is_err());
+    }
+
+    #[test]
+    fn convert_cell_raises_value_error_naming_row_and_column() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = convert_cell(py, "not-an-int", ColumnType::Int, 2, 1)
+                .expect_err("malformed int cell should raise");
+            let message = err.to_string();
+            assert!(message.contains("row 2"));
+            assert!(message.contains("column 1"));
+        });
+    }
+
+    #[test]
+    fn convert_cell_builds_lossless_decimal() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let value = convert_cell(py, "19.99", ColumnType::Decimal, 0, 0).unwrap();
+            let value = value.as_ref(py);
+            let text: String = value.str().unwrap().to_string();
+            assert_eq!(text, "19.99");
+        });
+    }
+}